@@ -18,10 +18,78 @@ pub struct DatabaseConfig {
     pub max_connections: u32,
 }
 
+#[derive(Debug, thiserror::Error)]
+#[error("invalid configuration: {}", .problems.join("; "))]
+pub struct ConfigError {
+    pub problems: Vec<String>,
+}
+
 impl Config {
-    pub fn from_env() -> Result<Self, Box<dyn std::error::Error>> {
-        // TODO: Implement environment-based configuration
-        Ok(Self::default())
+    pub fn from_env() -> Result<Self, ConfigError> {
+        let defaults = Self::default();
+        let mut problems = Vec::new();
+
+        let mut parse_env = |name: &str, default: u32| match std::env::var(name) {
+            Ok(value) => value.parse().unwrap_or_else(|_| {
+                problems.push(format!("{} must be a number, got {:?}", name, value));
+                default
+            }),
+            Err(_) => default,
+        };
+
+        let port = parse_env("SERVER_PORT", defaults.server.port as u32);
+        let max_connections = parse_env("DATABASE_MAX_CONNECTIONS", defaults.database.max_connections);
+
+        let config = Self {
+            server: ServerConfig {
+                host: std::env::var("SERVER_HOST").unwrap_or(defaults.server.host),
+                port: u16::try_from(port).unwrap_or_else(|_| {
+                    problems.push(format!("SERVER_PORT must be between 1 and 65535, got {}", port));
+                    defaults.server.port
+                }),
+            },
+            database: DatabaseConfig {
+                url: std::env::var("DATABASE_URL").unwrap_or_default(),
+                max_connections,
+            },
+        };
+
+        if let Err(e) = config.validate() {
+            problems.extend(e.problems);
+        }
+
+        if problems.is_empty() {
+            Ok(config)
+        } else {
+            Err(ConfigError { problems })
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        let mut problems = Vec::new();
+
+        if self.server.host.trim().is_empty() {
+            problems.push("SERVER_HOST must not be empty".to_string());
+        }
+        if self.server.port == 0 {
+            problems.push("SERVER_PORT must be between 1 and 65535".to_string());
+        }
+        if self.database.url.trim().is_empty() {
+            problems.push("DATABASE_URL is required".to_string());
+        } else if !self.database.url.starts_with("postgres://")
+            && !self.database.url.starts_with("postgresql://")
+        {
+            problems.push("DATABASE_URL must be a postgres:// URL".to_string());
+        }
+        if self.database.max_connections == 0 {
+            problems.push("DATABASE_MAX_CONNECTIONS must be greater than 0".to_string());
+        }
+
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(ConfigError { problems })
+        }
     }
 }
 
@@ -38,4 +106,36 @@ impl Default for Config {
             },
         }
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_config_is_valid() {
+        assert!(Config::default().validate().is_ok());
+    }
+
+    #[test]
+    fn reports_every_invalid_field_together() {
+        let config = Config {
+            server: ServerConfig {
+                host: " ".to_string(),
+                port: 0,
+            },
+            database: DatabaseConfig {
+                url: "mysql://localhost/arenax".to_string(),
+                max_connections: 0,
+            },
+        };
+
+        let err = config.validate().unwrap_err();
+
+        assert_eq!(err.problems.len(), 4, "{:?}", err.problems);
+        assert!(err.problems.iter().any(|p| p.contains("SERVER_HOST")));
+        assert!(err.problems.iter().any(|p| p.contains("SERVER_PORT")));
+        assert!(err.problems.iter().any(|p| p.contains("DATABASE_URL")));
+        assert!(err.problems.iter().any(|p| p.contains("DATABASE_MAX_CONNECTIONS")));
+    }
 }
\ No newline at end of file
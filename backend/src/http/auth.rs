@@ -0,0 +1,66 @@
+use actix_web::{web, HttpResponse};
+
+use crate::api_error::ApiError;
+use crate::middleware::auth::AuthenticatedUser;
+use crate::models::user::{LoginRequest, RefreshTokenRequest};
+use crate::service::auth_service::AuthService;
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/auth")
+            .route("/login", web::post().to(login))
+            .route("/refresh", web::post().to(refresh))
+            .route("/logout", web::post().to(logout)),
+    );
+}
+
+pub async fn login(
+    service: web::Data<AuthService>,
+    request: web::Json<LoginRequest>,
+) -> Result<HttpResponse, ApiError> {
+    let response = service.login(request.into_inner()).await?;
+    Ok(HttpResponse::Ok().json(response))
+}
+
+pub async fn refresh(
+    service: web::Data<AuthService>,
+    request: web::Json<RefreshTokenRequest>,
+) -> Result<HttpResponse, ApiError> {
+    let response = service.refresh_token(request.into_inner()).await?;
+    Ok(HttpResponse::Ok().json(response))
+}
+
+pub async fn logout(
+    service: web::Data<AuthService>,
+    user: AuthenticatedUser,
+    request: web::Json<RefreshTokenRequest>,
+) -> Result<HttpResponse, ApiError> {
+    service.logout(user.user_id, request.into_inner()).await?;
+    Ok(HttpResponse::NoContent().finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::http::StatusCode;
+    use actix_web::{test, App};
+
+    use crate::db::DbPool;
+
+    #[actix_web::test]
+    async fn logout_without_token_is_unauthorized() {
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(AuthService::new(DbPool)))
+                .service(web::scope("/api").configure(crate::http::configure)),
+        )
+        .await;
+
+        let req = test::TestRequest::post()
+            .uri("/api/auth/logout")
+            .set_json(RefreshTokenRequest { refresh_token: "refresh".to_string() })
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+    }
+}
\ No newline at end of file
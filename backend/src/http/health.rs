@@ -1,4 +1,103 @@
-// TODO: Implement health check endpoint with actix-web
-pub async fn health_check() -> String {
-    "Health check endpoint - TODO: Implement with actix-web".to_string()
+use std::future::Future;
+
+use actix_web::{web, HttpResponse};
+use serde::Serialize;
+
+use crate::db::{self, DbPool};
+
+pub trait DatabaseProbe {
+    fn is_up(&self) -> impl Future<Output = bool>;
+}
+
+impl DatabaseProbe for DbPool {
+    async fn is_up(&self) -> bool {
+        db::health_check(self).await.is_ok()
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct HealthResponse {
+    pub status: &'static str,
+    pub database: &'static str,
+}
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.route("/health", web::get().to(health_check::<DbPool>))
+        .route("/health/live", web::get().to(liveness));
+}
+
+pub async fn health_check<P: DatabaseProbe + 'static>(probe: web::Data<P>) -> HttpResponse {
+    let database_up = probe.is_up().await;
+
+    let response = HealthResponse {
+        status: if database_up { "ok" } else { "degraded" },
+        database: if database_up { "up" } else { "down" },
+    };
+
+    if database_up {
+        HttpResponse::Ok().json(response)
+    } else {
+        HttpResponse::ServiceUnavailable().json(response)
+    }
+}
+
+pub async fn liveness() -> HttpResponse {
+    HttpResponse::Ok().json(serde_json::json!({ "status": "ok" }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::http::StatusCode;
+    use actix_web::{test, App};
+    use serde_json::{json, Value};
+
+    struct DownProbe;
+
+    impl DatabaseProbe for DownProbe {
+        async fn is_up(&self) -> bool {
+            false
+        }
+    }
+
+    #[actix_web::test]
+    async fn reports_healthy_database() {
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(DbPool))
+                .configure(configure),
+        )
+        .await;
+
+        let req = test::TestRequest::get().uri("/health").to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        let body: Value = test::read_body_json(resp).await;
+        assert_eq!(body, json!({ "status": "ok", "database": "up" }));
+    }
+
+    #[actix_web::test]
+    async fn liveness_is_ok() {
+        let app = test::init_service(App::new().configure(configure)).await;
+
+        let req = test::TestRequest::get().uri("/health/live").to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+
+    #[actix_web::test]
+    async fn reports_unavailable_when_database_is_down() {
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(DownProbe))
+                .route("/health", web::get().to(health_check::<DownProbe>)),
+        )
+        .await;
+
+        let req = test::TestRequest::get().uri("/health").to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::SERVICE_UNAVAILABLE);
+        let body: Value = test::read_body_json(resp).await;
+        assert_eq!(body, json!({ "status": "degraded", "database": "down" }));
+    }
 }
\ No newline at end of file
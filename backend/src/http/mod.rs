@@ -1,5 +1,13 @@
+pub mod auth;
 pub mod health;
+pub mod tournaments;
 // TODO: Add more HTTP modules as implemented:
-// pub mod tournaments;
 // pub mod matches;
-// pub mod auth;
\ No newline at end of file
+
+use actix_web::web;
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.configure(health::configure)
+        .configure(auth::configure)
+        .configure(tournaments::configure);
+}
\ No newline at end of file
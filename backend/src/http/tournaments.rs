@@ -0,0 +1,88 @@
+use actix_web::middleware::from_fn;
+use actix_web::{web, HttpResponse};
+use uuid::Uuid;
+
+use crate::api_error::ApiError;
+use crate::middleware::auth::{require_auth, AuthenticatedUser};
+use crate::service::tournament_service::TournamentService;
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/users")
+            .wrap(from_fn(require_auth))
+            .route("/{user_id}/tournaments", web::get().to(get_user_active_tournaments)),
+    );
+}
+
+pub async fn get_user_active_tournaments(
+    service: web::Data<TournamentService>,
+    user: AuthenticatedUser,
+    user_id: web::Path<Uuid>,
+) -> Result<HttpResponse, ApiError> {
+    let user_id = user_id.into_inner();
+    if user.user_id != user_id {
+        return Err(ApiError::forbidden("Cannot view another user's tournaments"));
+    }
+
+    let summaries = service.get_user_active_tournaments(user_id).await?;
+    Ok(HttpResponse::Ok().json(summaries))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::dev::{Service, ServiceResponse};
+    use actix_web::http::StatusCode;
+    use actix_web::{test, App, HttpMessage};
+
+    use crate::db::DbPool;
+    use crate::service::auth_service::AuthService;
+
+    fn status<B>(result: Result<ServiceResponse<B>, actix_web::Error>) -> StatusCode {
+        match result {
+            Ok(resp) => resp.status(),
+            Err(e) => e.error_response().status(),
+        }
+    }
+
+    #[actix_web::test]
+    async fn requires_authentication() {
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(TournamentService::new(DbPool)))
+                .app_data(web::Data::new(AuthService::new(DbPool)))
+                .configure(configure),
+        )
+        .await;
+
+        let req = test::TestRequest::get()
+            .uri(&format!("/users/{}/tournaments", Uuid::new_v4()))
+            .to_request();
+        assert_eq!(status(test::try_call_service(&app, req).await), StatusCode::UNAUTHORIZED);
+    }
+
+    #[actix_web::test]
+    async fn only_lists_the_callers_own_tournaments() {
+        let user_id = Uuid::new_v4();
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(TournamentService::new(DbPool)))
+                .wrap_fn(move |req, srv| {
+                    req.extensions_mut().insert(AuthenticatedUser { user_id });
+                    srv.call(req)
+                })
+                .configure(configure),
+        )
+        .await;
+
+        let req = test::TestRequest::get()
+            .uri(&format!("/users/{}/tournaments", user_id))
+            .to_request();
+        assert_eq!(status(test::try_call_service(&app, req).await), StatusCode::OK);
+
+        let req = test::TestRequest::get()
+            .uri(&format!("/users/{}/tournaments", Uuid::new_v4()))
+            .to_request();
+        assert_eq!(status(test::try_call_service(&app, req).await), StatusCode::FORBIDDEN);
+    }
+}
\ No newline at end of file
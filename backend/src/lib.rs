@@ -2,5 +2,6 @@ pub mod api_error;
 pub mod config;
 pub mod db;
 pub mod http;
+pub mod middleware;
 pub mod models;
 pub mod service;
\ No newline at end of file
@@ -1,18 +1,60 @@
 use std::io;
+use std::time::Duration;
+
+use actix_web::{web, App, HttpServer};
 
 mod api_error;
 mod config;
 mod db;
 mod http;
+mod middleware;
 mod models;
 mod service;
 
-#[tokio::main]
+const TOURNAMENT_SCHEDULE_INTERVAL: Duration = Duration::from_secs(60);
+
+#[actix_web::main]
 async fn main() -> io::Result<()> {
-    println!("ArenaX Backend starting...");
+    let config = match config::Config::from_env() {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("Failed to load configuration:");
+            for problem in &e.problems {
+                eprintln!("  - {}", problem);
+            }
+            std::process::exit(1);
+        }
+    };
+
+    let pool = db::create_pool(&config.database)
+        .await
+        .map_err(|e| io::Error::other(e.to_string()))?;
+
+    let db_pool = web::Data::new(pool.clone());
+    let auth_service = web::Data::new(service::auth_service::AuthService::new(pool.clone()));
+    let tournament_service = web::Data::new(service::tournament_service::TournamentService::new(pool.clone()));
+
+    println!(
+        "ArenaX Backend starting on {}:{}...",
+        config.server.host, config.server.port
+    );
+
+    let schedule_task = tournament_service
+        .get_ref()
+        .clone()
+        .spawn_schedule_task(TOURNAMENT_SCHEDULE_INTERVAL);
 
-    // TODO: Initialize server with proper configuration
-    // For now, just exit successfully to test compilation
+    let result = HttpServer::new(move || {
+        App::new()
+            .app_data(db_pool.clone())
+            .app_data(auth_service.clone())
+            .app_data(tournament_service.clone())
+            .service(web::scope("/api").configure(http::configure))
+    })
+    .bind((config.server.host.clone(), config.server.port))?
+    .run()
+    .await;
 
-    Ok(())
+    schedule_task.abort();
+    result
 }
\ No newline at end of file
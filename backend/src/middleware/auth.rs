@@ -0,0 +1,125 @@
+use std::future::{ready, Ready};
+
+use actix_web::body::MessageBody;
+use actix_web::dev::{Payload, ServiceRequest, ServiceResponse};
+use actix_web::http::header::AUTHORIZATION;
+use actix_web::middleware::Next;
+use actix_web::{web, Error, FromRequest, HttpMessage, HttpRequest};
+use uuid::Uuid;
+
+use crate::api_error::ApiError;
+use crate::service::auth_service::AuthService;
+
+#[derive(Debug, Clone, Copy)]
+pub struct AuthenticatedUser {
+    pub user_id: Uuid,
+}
+
+fn authenticate(req: &HttpRequest) -> Result<AuthenticatedUser, ApiError> {
+    if let Some(user) = req.extensions().get::<AuthenticatedUser>() {
+        return Ok(*user);
+    }
+
+    let token = req
+        .headers()
+        .get(AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .ok_or_else(|| ApiError::unauthorized("Missing bearer token"))?;
+
+    let auth_service = req
+        .app_data::<web::Data<AuthService>>()
+        .ok_or_else(|| ApiError::internal_error("Auth service not configured"))?;
+
+    let user_id = auth_service.verify_token(token).map_err(reject_token)?;
+    Ok(AuthenticatedUser { user_id })
+}
+
+fn reject_token(err: ApiError) -> ApiError {
+    match err.code.as_str() {
+        "UNAUTHORIZED" | "BAD_REQUEST" => ApiError::unauthorized("Invalid or expired token"),
+        _ => err,
+    }
+}
+
+pub async fn require_auth(
+    req: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<impl MessageBody>, Error> {
+    let user = authenticate(req.request())?;
+    req.extensions_mut().insert(user);
+    next.call(req).await
+}
+
+impl FromRequest for AuthenticatedUser {
+    type Error = ApiError;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _payload: &mut Payload) -> Self::Future {
+        ready(authenticate(req))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::http::StatusCode;
+    use actix_web::{test, App, HttpResponse};
+
+    use crate::db::DbPool;
+
+    async fn whoami(user: AuthenticatedUser) -> HttpResponse {
+        HttpResponse::Ok().body(user.user_id.to_string())
+    }
+
+    #[actix_web::test]
+    async fn missing_token_is_unauthorized() {
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(AuthService::new(DbPool)))
+                .route("/whoami", web::get().to(whoami)),
+        )
+        .await;
+
+        let req = test::TestRequest::get().uri("/whoami").to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[actix_web::test]
+    async fn verifier_failure_is_internal_error() {
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(AuthService::new(DbPool)))
+                .route("/whoami", web::get().to(whoami)),
+        )
+        .await;
+
+        // verify_token is still a stub that fails with INTERNAL_ERROR for every token.
+        let req = test::TestRequest::get()
+            .uri("/whoami")
+            .insert_header((AUTHORIZATION, "Bearer not-a-token"))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    }
+
+    #[actix_web::test]
+    async fn rejected_tokens_are_unauthorized() {
+        for err in [
+            ApiError::unauthorized("Token expired"),
+            ApiError::bad_request("Malformed token"),
+        ] {
+            let err = reject_token(err);
+            assert_eq!(err.code, "UNAUTHORIZED");
+            assert_eq!(err.message, "Invalid or expired token");
+        }
+    }
+
+    #[actix_web::test]
+    async fn verifier_internal_errors_pass_through() {
+        let err = reject_token(ApiError::internal_error("Signing key unavailable"));
+        assert_eq!(err.code, "INTERNAL_ERROR");
+        assert_eq!(err.message, "Signing key unavailable");
+    }
+}
@@ -0,0 +1,25 @@
+use chrono::{DateTime, TimeZone, Utc};
+use uuid::Uuid;
+
+use crate::models::match_model::{Match, MatchStatus};
+
+pub fn at(secs: i64) -> DateTime<Utc> {
+    Utc.timestamp_opt(1_700_000_000 + secs, 0).unwrap()
+}
+
+pub fn game(player1_id: Uuid, player2_id: Uuid, status: MatchStatus) -> Match {
+    Match {
+        id: Uuid::new_v4(),
+        tournament_id: None,
+        player1_id,
+        player2_id,
+        game_type: "chess".to_string(),
+        status: status.to_string(),
+        winner_id: None,
+        score_player1: None,
+        score_player2: None,
+        started_at: None,
+        completed_at: None,
+        created_at: at(0),
+    }
+}
@@ -43,6 +43,65 @@ pub struct MatchResponse {
     pub tournament_name: Option<String>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HeadToHeadResponse {
+    pub player_a_id: Uuid,
+    pub player_b_id: Uuid,
+    pub game_type: Option<String>,
+    pub total_matches: i32,
+    pub player_a_wins: i32,
+    pub player_b_wins: i32,
+    pub draws: i32,
+    pub recent_matches: Vec<Match>,
+}
+
+impl HeadToHeadResponse {
+    pub fn from_matches(
+        player_a_id: Uuid,
+        player_b_id: Uuid,
+        game_type: Option<String>,
+        matches: &[Match],
+        recent_limit: usize,
+    ) -> Self {
+        let completed = MatchStatus::Completed.to_string();
+
+        let mut played: Vec<&Match> = matches
+            .iter()
+            .filter(|m| m.status == completed)
+            .filter(|m| {
+                (m.player1_id == player_a_id && m.player2_id == player_b_id)
+                    || (m.player1_id == player_b_id && m.player2_id == player_a_id)
+            })
+            .filter(|m| m.winner_id.is_none_or(|winner_id| winner_id == player_a_id || winner_id == player_b_id))
+            .filter(|m| game_type.as_ref().is_none_or(|game| &m.game_type == game))
+            .collect();
+
+        let mut player_a_wins = 0;
+        let mut player_b_wins = 0;
+        let mut draws = 0;
+        for m in &played {
+            match m.winner_id {
+                Some(winner_id) if winner_id == player_a_id => player_a_wins += 1,
+                Some(_) => player_b_wins += 1,
+                None => draws += 1,
+            }
+        }
+
+        played.sort_by_key(|m| std::cmp::Reverse(m.completed_at));
+
+        Self {
+            player_a_id,
+            player_b_id,
+            game_type,
+            total_matches: played.len() as i32,
+            player_a_wins,
+            player_b_wins,
+            draws,
+            recent_matches: played.into_iter().take(recent_limit).cloned().collect(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum MatchStatus {
     Pending,
@@ -62,4 +121,85 @@ impl std::fmt::Display for MatchStatus {
             MatchStatus::Cancelled => write!(f, "cancelled"),
         }
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::fixtures::{at, game};
+
+    fn played(
+        player1_id: Uuid,
+        player2_id: Uuid,
+        game_type: &str,
+        status: MatchStatus,
+        winner_id: Option<Uuid>,
+        completed_secs: i64,
+    ) -> Match {
+        Match {
+            game_type: game_type.to_string(),
+            winner_id,
+            completed_at: Some(at(completed_secs)),
+            ..game(player1_id, player2_id, status)
+        }
+    }
+
+    #[test]
+    fn head_to_head_tallies_only_completed_matches_between_both_players() {
+        let (a, b, c) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let matches = vec![
+            played(a, b, "chess", MatchStatus::Completed, Some(a), 10),
+            played(b, a, "chess", MatchStatus::Completed, Some(a), 20),
+            played(b, a, "chess", MatchStatus::Completed, Some(b), 30),
+            played(a, b, "chess", MatchStatus::Completed, None, 40),
+            played(a, b, "chess", MatchStatus::InProgress, None, 50),
+            played(a, c, "chess", MatchStatus::Completed, Some(a), 60),
+            played(a, b, "chess", MatchStatus::Completed, Some(c), 70),
+        ];
+
+        let record = HeadToHeadResponse::from_matches(a, b, None, &matches, 10);
+
+        assert_eq!(record.total_matches, 4);
+        assert_eq!(record.player_a_wins, 2);
+        assert_eq!(record.player_b_wins, 1);
+        assert_eq!(record.draws, 1);
+        assert_eq!(
+            record.total_matches,
+            record.player_a_wins + record.player_b_wins + record.draws
+        );
+    }
+
+    #[test]
+    fn head_to_head_filters_by_game_type() {
+        let (a, b) = (Uuid::new_v4(), Uuid::new_v4());
+        let matches = vec![
+            played(a, b, "chess", MatchStatus::Completed, Some(a), 10),
+            played(a, b, "go", MatchStatus::Completed, Some(b), 20),
+            played(b, a, "go", MatchStatus::Completed, Some(b), 30),
+        ];
+
+        let record = HeadToHeadResponse::from_matches(a, b, Some("go".to_string()), &matches, 10);
+
+        assert_eq!(record.total_matches, 2);
+        assert_eq!(record.player_a_wins, 0);
+        assert_eq!(record.player_b_wins, 2);
+    }
+
+    #[test]
+    fn head_to_head_recent_matches_are_newest_first_and_limited() {
+        let (a, b) = (Uuid::new_v4(), Uuid::new_v4());
+        let matches: Vec<Match> = [30, 10, 50, 20, 40]
+            .into_iter()
+            .map(|secs| played(a, b, "chess", MatchStatus::Completed, Some(a), secs))
+            .collect();
+
+        let record = HeadToHeadResponse::from_matches(a, b, None, &matches, 3);
+
+        let recent: Vec<_> = record.recent_matches.iter().map(|m| m.completed_at).collect();
+        assert_eq!(
+            recent,
+            vec![Some(at(50)), Some(at(40)), Some(at(30))]
+        );
+        assert_eq!(record.total_matches, 5);
+    }
 }
\ No newline at end of file
@@ -4,4 +4,7 @@ pub mod tournament;
 pub mod match_model;
 pub mod wallet;
 
+#[cfg(test)]
+pub mod fixtures;
+
 // TODO: Add more model modules as implemented
\ No newline at end of file
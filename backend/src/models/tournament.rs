@@ -2,6 +2,8 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::models::match_model::{Match, MatchStatus};
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Tournament {
     pub id: Uuid,
@@ -15,6 +17,8 @@ pub struct Tournament {
     pub current_participants: i32,
     pub status: String,
     pub visibility: String,
+    pub registration_opens_at: Option<DateTime<Utc>>,
+    pub registration_deadline: Option<DateTime<Utc>>,
     pub start_time: DateTime<Utc>,
     pub end_time: Option<DateTime<Utc>>,
     pub created_by: Uuid,
@@ -31,9 +35,46 @@ pub struct CreateTournamentRequest {
     pub entry_fee: i32, // TODO: Use Decimal when rust_decimal is added
     pub max_participants: i32,
     pub visibility: String,
+    pub registration_opens_at: Option<DateTime<Utc>>,
+    pub registration_deadline: Option<DateTime<Utc>>,
     pub start_time: DateTime<Utc>,
 }
 
+impl Tournament {
+    pub fn scheduled_status(&self, now: DateTime<Utc>) -> Option<TournamentStatus> {
+        let reached = |at: Option<DateTime<Utc>>| at.is_some_and(|at| at <= now);
+        let started = self.start_time <= now;
+
+        match self.status.parse::<TournamentStatus>().ok()? {
+            TournamentStatus::Draft if started => Some(TournamentStatus::Cancelled),
+            TournamentStatus::Draft if reached(self.registration_opens_at) => {
+                Some(TournamentStatus::RegistrationOpen)
+            }
+            TournamentStatus::RegistrationOpen
+                if reached(self.registration_deadline)
+                    || started
+                    || self.current_participants >= self.max_participants =>
+            {
+                Some(TournamentStatus::RegistrationClosed)
+            }
+            TournamentStatus::RegistrationClosed if started => {
+                let min_participants = self
+                    .tournament_type
+                    .parse::<TournamentType>()
+                    .map(|tournament_type| tournament_type.min_participants())
+                    .unwrap_or(2);
+
+                if self.current_participants >= min_participants {
+                    Some(TournamentStatus::InProgress)
+                } else {
+                    Some(TournamentStatus::Cancelled)
+                }
+            }
+            _ => None,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TournamentResponse {
     #[serde(flatten)]
@@ -43,6 +84,123 @@ pub struct TournamentResponse {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserTournamentSummary {
+    pub tournament: Tournament,
+    pub next_match: Option<Match>,
+    pub wins: i32,
+    pub losses: i32,
+    pub draws: i32,
+    pub matches_played: i32,
+}
+
+impl UserTournamentSummary {
+    pub fn from_matches(user_id: Uuid, tournament: Tournament, matches: &[Match]) -> Self {
+        let completed = MatchStatus::Completed.to_string();
+        let pending = MatchStatus::Pending.to_string();
+        let in_progress = MatchStatus::InProgress.to_string();
+
+        let user_matches = matches
+            .iter()
+            .filter(|m| m.player1_id == user_id || m.player2_id == user_id);
+
+        let mut wins = 0;
+        let mut losses = 0;
+        let mut draws = 0;
+        let mut next_match: Option<&Match> = None;
+        for m in user_matches {
+            if m.status == completed {
+                match m.winner_id {
+                    Some(winner_id) if winner_id == user_id => wins += 1,
+                    Some(_) => losses += 1,
+                    None => draws += 1,
+                }
+            } else if (m.status == pending || m.status == in_progress)
+                && next_match.is_none_or(|next| m.created_at < next.created_at)
+            {
+                next_match = Some(m);
+            }
+        }
+
+        Self {
+            tournament,
+            next_match: next_match.cloned(),
+            wins,
+            losses,
+            draws,
+            matches_played: wins + losses + draws,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TournamentAnalytics {
+    pub tournament_id: Uuid,
+    pub participant_count: i32,
+    pub total_matches: i32,
+    pub completed_matches: i32,
+    pub completion_rate: f64,
+    pub average_match_duration_secs: Option<i64>,
+    pub disputed_matches: i32,
+    pub dispute_rate: f64,
+    pub prize_pool: i32, // TODO: Use Decimal when rust_decimal is added
+    pub entry_fee: i32, // TODO: Use Decimal when rust_decimal is added
+    pub prize_distribution: Option<Vec<PrizePayout>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PrizePayout {
+    pub user_id: Uuid,
+    pub placement: i32,
+    pub amount: i32, // TODO: Use Decimal when rust_decimal is added
+}
+
+impl TournamentAnalytics {
+    pub fn from_matches(tournament: &Tournament, matches: &[Match]) -> Self {
+        let completed = MatchStatus::Completed.to_string();
+        let disputed = MatchStatus::Disputed.to_string();
+
+        let total_matches = matches.len() as i32;
+        let completed_matches = matches.iter().filter(|m| m.status == completed).count() as i32;
+        let disputed_matches = matches.iter().filter(|m| m.status == disputed).count() as i32;
+
+        let durations: Vec<i64> = matches
+            .iter()
+            .filter_map(|m| match (m.started_at, m.completed_at) {
+                (Some(started), Some(completed)) => Some((completed - started).num_seconds()),
+                _ => None,
+            })
+            .collect();
+
+        let rate = |count: i32| {
+            if total_matches == 0 {
+                0.0
+            } else {
+                count as f64 / total_matches as f64
+            }
+        };
+
+        Self {
+            tournament_id: tournament.id,
+            participant_count: tournament.current_participants,
+            total_matches,
+            completed_matches,
+            completion_rate: rate(completed_matches),
+            average_match_duration_secs: if durations.is_empty() {
+                None
+            } else {
+                Some(durations.iter().sum::<i64>() / durations.len() as i64)
+            },
+            disputed_matches,
+            dispute_rate: rate(disputed_matches),
+            prize_pool: tournament.prize_pool,
+            entry_fee: tournament.entry_fee,
+            // TODO: Fill from prize payout records once they are stored
+            prize_distribution: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum TournamentStatus {
     Draft,
     RegistrationOpen,
@@ -52,6 +210,22 @@ pub enum TournamentStatus {
     Cancelled,
 }
 
+impl TournamentStatus {
+    pub fn can_transition_to(&self, next: TournamentStatus) -> bool {
+        use TournamentStatus::*;
+
+        matches!(
+            (self, next),
+            (Draft, RegistrationOpen)
+                | (RegistrationOpen, RegistrationClosed)
+                | (RegistrationClosed, RegistrationOpen)
+                | (RegistrationClosed, InProgress)
+                | (InProgress, Completed)
+                | (Draft | RegistrationOpen | RegistrationClosed | InProgress, Cancelled)
+        )
+    }
+}
+
 impl std::fmt::Display for TournamentStatus {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -63,4 +237,351 @@ impl std::fmt::Display for TournamentStatus {
             TournamentStatus::Cancelled => write!(f, "cancelled"),
         }
     }
+}
+
+impl std::str::FromStr for TournamentStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "draft" => Ok(TournamentStatus::Draft),
+            "registration_open" => Ok(TournamentStatus::RegistrationOpen),
+            "registration_closed" => Ok(TournamentStatus::RegistrationClosed),
+            "in_progress" => Ok(TournamentStatus::InProgress),
+            "completed" => Ok(TournamentStatus::Completed),
+            "cancelled" => Ok(TournamentStatus::Cancelled),
+            _ => Err(format!("Unknown tournament status: {}", s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TournamentType {
+    SingleElimination,
+    DoubleElimination,
+    RoundRobin,
+    Swiss,
+}
+
+impl TournamentType {
+    pub fn min_participants(&self) -> i32 {
+        match self {
+            TournamentType::SingleElimination => 2,
+            TournamentType::DoubleElimination => 4,
+            TournamentType::RoundRobin => 3,
+            TournamentType::Swiss => 4,
+        }
+    }
+
+    pub fn is_elimination(&self) -> bool {
+        matches!(self, TournamentType::SingleElimination | TournamentType::DoubleElimination)
+    }
+}
+
+impl std::fmt::Display for TournamentType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TournamentType::SingleElimination => write!(f, "single_elimination"),
+            TournamentType::DoubleElimination => write!(f, "double_elimination"),
+            TournamentType::RoundRobin => write!(f, "round_robin"),
+            TournamentType::Swiss => write!(f, "swiss"),
+        }
+    }
+}
+
+impl std::str::FromStr for TournamentType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "single_elimination" => Ok(TournamentType::SingleElimination),
+            "double_elimination" => Ok(TournamentType::DoubleElimination),
+            "round_robin" => Ok(TournamentType::RoundRobin),
+            "swiss" => Ok(TournamentType::Swiss),
+            _ => Err(format!("Unknown tournament type: {}", s)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    use crate::models::fixtures::{at, game};
+
+    fn tournament() -> Tournament {
+        Tournament {
+            id: Uuid::new_v4(),
+            name: "Weekly Cup".to_string(),
+            description: None,
+            game_type: "chess".to_string(),
+            tournament_type: TournamentType::SingleElimination.to_string(),
+            entry_fee: 10,
+            prize_pool: 80,
+            max_participants: 8,
+            current_participants: 8,
+            status: TournamentStatus::InProgress.to_string(),
+            visibility: "public".to_string(),
+            registration_opens_at: None,
+            registration_deadline: None,
+            start_time: at(0),
+            end_time: None,
+            created_by: Uuid::new_v4(),
+            created_at: at(0),
+            updated_at: at(0),
+        }
+    }
+
+    fn timed(status: MatchStatus, duration_secs: Option<i64>) -> Match {
+        Match {
+            started_at: duration_secs.map(|_| at(0)),
+            completed_at: duration_secs.map(|secs| at(0) + Duration::seconds(secs)),
+            ..game(Uuid::new_v4(), Uuid::new_v4(), status)
+        }
+    }
+
+    #[test]
+    fn analytics_computes_rates_and_average_duration() {
+        let tournament = Tournament {
+            status: TournamentStatus::Completed.to_string(),
+            end_time: Some(at(3600)),
+            ..tournament()
+        };
+        let matches = vec![
+            timed(MatchStatus::Completed, Some(600)),
+            timed(MatchStatus::Completed, Some(1200)),
+            timed(MatchStatus::Disputed, Some(900)),
+            timed(MatchStatus::Pending, None),
+        ];
+
+        let analytics = TournamentAnalytics::from_matches(&tournament, &matches);
+
+        assert_eq!(analytics.tournament_id, tournament.id);
+        assert_eq!(analytics.participant_count, 8);
+        assert_eq!(analytics.total_matches, 4);
+        assert_eq!(analytics.completed_matches, 2);
+        assert_eq!(analytics.completion_rate, 0.5);
+        assert_eq!(analytics.disputed_matches, 1);
+        assert_eq!(analytics.dispute_rate, 0.25);
+        assert_eq!(analytics.average_match_duration_secs, Some(900));
+        assert_eq!(analytics.prize_pool, 80);
+        assert_eq!(analytics.entry_fee, 10);
+        assert!(analytics.prize_distribution.is_none());
+    }
+
+    #[test]
+    fn analytics_without_matches_has_zero_rates() {
+        let analytics = TournamentAnalytics::from_matches(&tournament(), &[]);
+
+        assert_eq!(analytics.total_matches, 0);
+        assert_eq!(analytics.completion_rate, 0.0);
+        assert_eq!(analytics.dispute_rate, 0.0);
+        assert_eq!(analytics.average_match_duration_secs, None);
+    }
+
+    fn scheduled() -> Tournament {
+        Tournament {
+            status: TournamentStatus::Draft.to_string(),
+            current_participants: 4,
+            registration_opens_at: Some(at(100)),
+            registration_deadline: Some(at(200)),
+            start_time: at(300),
+            ..tournament()
+        }
+    }
+
+    fn advance(tournament: &mut Tournament, now: DateTime<Utc>) -> Option<TournamentStatus> {
+        let next = tournament.scheduled_status(now);
+        if let Some(status) = next {
+            tournament.status = status.to_string();
+        }
+        next
+    }
+
+    #[test]
+    fn draft_opens_registration_once_window_arrives() {
+        let mut tournament = scheduled();
+
+        assert_eq!(advance(&mut tournament, at(99)), None);
+        assert_eq!(advance(&mut tournament, at(100)), Some(TournamentStatus::RegistrationOpen));
+        assert_eq!(advance(&mut tournament, at(100)), None);
+    }
+
+    #[test]
+    fn draft_past_start_time_is_cancelled_without_opening_registration() {
+        let mut tournament = scheduled();
+
+        assert_eq!(advance(&mut tournament, at(300)), Some(TournamentStatus::Cancelled));
+        assert_eq!(advance(&mut tournament, at(300)), None);
+    }
+
+    #[test]
+    fn registration_closes_at_deadline() {
+        let mut tournament = scheduled();
+        tournament.status = TournamentStatus::RegistrationOpen.to_string();
+
+        assert_eq!(advance(&mut tournament, at(199)), None);
+        assert_eq!(advance(&mut tournament, at(200)), Some(TournamentStatus::RegistrationClosed));
+        assert_eq!(advance(&mut tournament, at(200)), None);
+    }
+
+    #[test]
+    fn registration_closes_at_start_time_without_deadline() {
+        let mut tournament = scheduled();
+        tournament.status = TournamentStatus::RegistrationOpen.to_string();
+        tournament.registration_deadline = None;
+
+        assert_eq!(advance(&mut tournament, at(299)), None);
+        assert_eq!(advance(&mut tournament, at(300)), Some(TournamentStatus::RegistrationClosed));
+        // The close is not repeated; the only remaining step is the start itself.
+        assert_eq!(advance(&mut tournament, at(300)), Some(TournamentStatus::InProgress));
+        assert_eq!(advance(&mut tournament, at(300)), None);
+    }
+
+    #[test]
+    fn registration_closes_when_full() {
+        let mut tournament = scheduled();
+        tournament.status = TournamentStatus::RegistrationOpen.to_string();
+        tournament.current_participants = tournament.max_participants;
+
+        assert_eq!(advance(&mut tournament, at(150)), Some(TournamentStatus::RegistrationClosed));
+        assert_eq!(advance(&mut tournament, at(150)), None);
+    }
+
+    #[test]
+    fn closed_registration_starts_at_start_time() {
+        let mut tournament = scheduled();
+        tournament.status = TournamentStatus::RegistrationClosed.to_string();
+
+        assert_eq!(advance(&mut tournament, at(299)), None);
+        assert_eq!(advance(&mut tournament, at(300)), Some(TournamentStatus::InProgress));
+        assert_eq!(advance(&mut tournament, at(300)), None);
+    }
+
+    fn between(player1_id: Uuid, player2_id: Uuid, status: MatchStatus, winner_id: Option<Uuid>, created_secs: i64) -> Match {
+        Match {
+            winner_id,
+            created_at: at(created_secs),
+            ..game(player1_id, player2_id, status)
+        }
+    }
+
+    #[test]
+    fn user_summary_tallies_record_and_picks_earliest_open_match() {
+        let user = Uuid::new_v4();
+        let (a, b, c) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+
+        let earliest_open = between(c, user, MatchStatus::InProgress, None, 20);
+        let matches = vec![
+            between(user, a, MatchStatus::Completed, Some(user), 0),
+            between(b, user, MatchStatus::Completed, Some(b), 5),
+            between(user, c, MatchStatus::Completed, None, 7),
+            between(user, b, MatchStatus::Pending, None, 30),
+            earliest_open.clone(),
+            between(a, b, MatchStatus::Completed, Some(a), 1),
+            between(a, c, MatchStatus::Pending, None, 10),
+        ];
+
+        let summary = UserTournamentSummary::from_matches(user, tournament(), &matches);
+
+        assert_eq!(summary.wins, 1);
+        assert_eq!(summary.losses, 1);
+        assert_eq!(summary.draws, 1);
+        assert_eq!(summary.matches_played, 3);
+        assert_eq!(summary.next_match.map(|m| m.id), Some(earliest_open.id));
+    }
+
+    #[test]
+    fn user_summary_without_open_matches_has_no_next_match() {
+        let user = Uuid::new_v4();
+        let matches = vec![between(user, Uuid::new_v4(), MatchStatus::Completed, Some(user), 0)];
+
+        let summary = UserTournamentSummary::from_matches(user, tournament(), &matches);
+
+        assert_eq!(summary.wins, 1);
+        assert!(summary.next_match.is_none());
+    }
+
+    #[test]
+    fn starts_at_start_time_with_minimum_participants() {
+        let mut tournament = scheduled();
+        tournament.status = TournamentStatus::RegistrationClosed.to_string();
+        tournament.current_participants = TournamentType::SingleElimination.min_participants();
+
+        assert_eq!(tournament.scheduled_status(tournament.start_time), Some(TournamentStatus::InProgress));
+    }
+
+    #[test]
+    fn cancels_at_start_time_below_minimum_participants() {
+        let mut tournament = scheduled();
+        tournament.status = TournamentStatus::RegistrationClosed.to_string();
+        tournament.tournament_type = TournamentType::DoubleElimination.to_string();
+        tournament.current_participants = TournamentType::DoubleElimination.min_participants() - 1;
+
+        assert_eq!(tournament.scheduled_status(tournament.start_time), Some(TournamentStatus::Cancelled));
+    }
+
+    #[test]
+    fn unknown_tournament_type_needs_two_participants_to_start() {
+        let mut tournament = scheduled();
+        tournament.status = TournamentStatus::RegistrationClosed.to_string();
+        tournament.tournament_type = "king_of_the_hill".to_string();
+
+        tournament.current_participants = 2;
+        assert_eq!(tournament.scheduled_status(tournament.start_time), Some(TournamentStatus::InProgress));
+
+        tournament.current_participants = 1;
+        assert_eq!(tournament.scheduled_status(tournament.start_time), Some(TournamentStatus::Cancelled));
+    }
+
+    const ALL_STATUSES: [TournamentStatus; 6] = [
+        TournamentStatus::Draft,
+        TournamentStatus::RegistrationOpen,
+        TournamentStatus::RegistrationClosed,
+        TournamentStatus::InProgress,
+        TournamentStatus::Completed,
+        TournamentStatus::Cancelled,
+    ];
+
+    #[test]
+    fn status_transitions_follow_the_lifecycle_table() {
+        use TournamentStatus::*;
+
+        let allowed = [
+            (Draft, RegistrationOpen),
+            (Draft, Cancelled),
+            (RegistrationOpen, RegistrationClosed),
+            (RegistrationOpen, Cancelled),
+            (RegistrationClosed, RegistrationOpen),
+            (RegistrationClosed, InProgress),
+            (RegistrationClosed, Cancelled),
+            (InProgress, Completed),
+            (InProgress, Cancelled),
+        ];
+
+        for from in ALL_STATUSES {
+            for to in ALL_STATUSES {
+                assert_eq!(
+                    from.can_transition_to(to),
+                    allowed.contains(&(from, to)),
+                    "{} -> {}",
+                    from,
+                    to
+                );
+            }
+        }
+
+        assert!(!Completed.can_transition_to(InProgress));
+        assert!(!Draft.can_transition_to(InProgress));
+        assert!(ALL_STATUSES.iter().all(|&to| !Cancelled.can_transition_to(to)));
+    }
+
+    #[test]
+    fn status_round_trips_through_display_and_from_str() {
+        for status in ALL_STATUSES {
+            assert_eq!(status.to_string().parse::<TournamentStatus>(), Ok(status));
+        }
+        assert!("archived".parse::<TournamentStatus>().is_err());
+    }
 }
\ No newline at end of file
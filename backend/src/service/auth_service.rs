@@ -1,6 +1,6 @@
 use crate::api_error::ApiError;
 use crate::db::DbPool;
-use crate::models::user::{User, CreateUserRequest, LoginRequest, AuthResponse};
+use crate::models::user::{User, CreateUserRequest, LoginRequest, AuthResponse, RefreshTokenRequest};
 use uuid::Uuid;
 
 #[derive(Clone)]
@@ -23,6 +23,16 @@ impl AuthService {
         Err(ApiError::internal_error("Auth service not yet implemented"))
     }
 
+    pub async fn refresh_token(&self, _request: RefreshTokenRequest) -> Result<AuthResponse, ApiError> {
+        // TODO: Implement refresh token rotation
+        Err(ApiError::internal_error("Auth service not yet implemented"))
+    }
+
+    pub async fn logout(&self, _user_id: Uuid, _request: RefreshTokenRequest) -> Result<(), ApiError> {
+        // TODO: Implement session revocation for the refresh token
+        Err(ApiError::internal_error("Auth service not yet implemented"))
+    }
+
     pub fn verify_token(&self, _token: &str) -> Result<Uuid, ApiError> {
         // TODO: Implement JWT token verification
         Err(ApiError::internal_error("Token verification not yet implemented"))
@@ -1,8 +1,10 @@
 use crate::api_error::ApiError;
 use crate::db::DbPool;
-use crate::models::match_model::{Match, MatchResult};
+use crate::models::match_model::{CreateMatchRequest, HeadToHeadResponse, Match, MatchResult};
 use uuid::Uuid;
 
+const HEAD_TO_HEAD_RECENT_MATCHES: usize = 5;
+
 #[derive(Clone)]
 pub struct MatchService {
     pool: DbPool,
@@ -13,6 +15,15 @@ impl MatchService {
         Self { pool }
     }
 
+    pub async fn create_match(&self, request: CreateMatchRequest) -> Result<Match, ApiError> {
+        if request.player1_id == request.player2_id {
+            return Err(ApiError::bad_request("A player cannot be matched against themselves"));
+        }
+
+        // TODO: Implement match creation with database
+        Err(ApiError::internal_error("Match service not yet implemented"))
+    }
+
     pub async fn get_match(&self, _match_id: Uuid, _user_id: Option<Uuid>) -> Result<Match, ApiError> {
         // TODO: Implement match retrieval from database
         Err(ApiError::not_found("Match not found"))
@@ -37,4 +48,49 @@ impl MatchService {
         // TODO: Implement leaderboard with ELO calculations
         Ok(vec![])
     }
+
+    pub async fn get_head_to_head(
+        &self,
+        player_a_id: Uuid,
+        player_b_id: Uuid,
+        game_type: Option<String>,
+    ) -> Result<HeadToHeadResponse, ApiError> {
+        if player_a_id == player_b_id {
+            return Err(ApiError::bad_request("Head-to-head requires two different players"));
+        }
+
+        // TODO: Load completed matches between both players from database
+        let matches: Vec<Match> = vec![];
+
+        Ok(HeadToHeadResponse::from_matches(
+            player_a_id,
+            player_b_id,
+            game_type,
+            &matches,
+            HEAD_TO_HEAD_RECENT_MATCHES,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn rejects_self_match() {
+        let service = MatchService::new(DbPool);
+        let player_id = Uuid::new_v4();
+
+        let err = service
+            .create_match(CreateMatchRequest {
+                tournament_id: None,
+                player1_id: player_id,
+                player2_id: player_id,
+                game_type: "chess".to_string(),
+            })
+            .await
+            .unwrap_err();
+
+        assert_eq!(err.code, "BAD_REQUEST");
+    }
 }
\ No newline at end of file
@@ -1,8 +1,16 @@
 use crate::api_error::ApiError;
 use crate::db::DbPool;
-use crate::models::tournament::{Tournament, CreateTournamentRequest};
+use crate::models::match_model::Match;
+use crate::models::tournament::{
+    Tournament, CreateTournamentRequest, TournamentAnalytics, TournamentStatus, TournamentType,
+    UserTournamentSummary,
+};
+use chrono::Utc;
+use std::time::Duration;
 use uuid::Uuid;
 
+const MAX_TOURNAMENT_PARTICIPANTS: i32 = 1024;
+
 #[derive(Clone)]
 pub struct TournamentService {
     pool: DbPool,
@@ -16,8 +24,10 @@ impl TournamentService {
     pub async fn create_tournament(
         &self,
         _creator_id: Uuid,
-        _request: CreateTournamentRequest,
+        request: CreateTournamentRequest,
     ) -> Result<Tournament, ApiError> {
+        Self::validate_tournament_creation(&request)?;
+
         // TODO: Implement tournament creation with database
         Err(ApiError::internal_error("Tournament service not yet implemented"))
     }
@@ -38,4 +48,207 @@ impl TournamentService {
         // TODO: Implement tournament retrieval
         Err(ApiError::not_found("Tournament not found"))
     }
+
+    pub async fn update_tournament_status(
+        &self,
+        id: Uuid,
+        status: TournamentStatus,
+    ) -> Result<Tournament, ApiError> {
+        let tournament = self.get_tournament(id).await?;
+        let old_status: TournamentStatus = tournament
+            .status
+            .parse()
+            .map_err(ApiError::internal_error)?;
+
+        if !old_status.can_transition_to(status) {
+            return Err(ApiError::bad_request(format!(
+                "Cannot move tournament from {} to {}",
+                old_status, status
+            )));
+        }
+
+        // TODO: Implement tournament status update and publish the old/new status change
+        Err(ApiError::internal_error("Tournament service not yet implemented"))
+    }
+
+    pub async fn process_tournament_schedules(&self) -> Result<u32, ApiError> {
+        let now = Utc::now();
+
+        // TODO: Load draft, registration_open and registration_closed tournaments from database
+        let tournaments: Vec<Tournament> = vec![];
+
+        let mut transitioned = 0;
+        for tournament in tournaments {
+            let Some(next_status) = tournament.scheduled_status(now) else {
+                continue;
+            };
+
+            match self.update_tournament_status(tournament.id, next_status).await {
+                Ok(_) => transitioned += 1,
+                Err(e) => eprintln!(
+                    "Failed to move tournament {} to {}: {}",
+                    tournament.id, next_status, e
+                ),
+            }
+        }
+
+        Ok(transitioned)
+    }
+
+    pub fn spawn_schedule_task(self, interval: Duration) -> tokio::task::JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            loop {
+                ticker.tick().await;
+                if let Err(e) = self.process_tournament_schedules().await {
+                    eprintln!("Tournament schedule processing failed: {}", e);
+                }
+            }
+        })
+    }
+
+    pub async fn get_tournament_analytics(&self, tournament_id: Uuid) -> Result<TournamentAnalytics, ApiError> {
+        let tournament = self.get_tournament(tournament_id).await?;
+
+        // TODO: Load the tournament's matches from database
+        let matches: Vec<Match> = vec![];
+
+        Ok(TournamentAnalytics::from_matches(&tournament, &matches))
+    }
+
+    pub async fn get_user_active_tournaments(&self, user_id: Uuid) -> Result<Vec<UserTournamentSummary>, ApiError> {
+        // TODO: Load registration_open, registration_closed and in_progress tournaments the user joined
+        let tournaments: Vec<Tournament> = vec![];
+
+        let mut summaries = Vec::with_capacity(tournaments.len());
+        for tournament in tournaments {
+            // TODO: Load the tournament's matches from database
+            let matches: Vec<Match> = vec![];
+            summaries.push(UserTournamentSummary::from_matches(user_id, tournament, &matches));
+        }
+
+        Ok(summaries)
+    }
+
+    fn validate_tournament_creation(request: &CreateTournamentRequest) -> Result<(), ApiError> {
+        let tournament_type: TournamentType = request
+            .tournament_type
+            .parse()
+            .map_err(ApiError::bad_request)?;
+
+        if let (Some(opens_at), Some(deadline)) = (request.registration_opens_at, request.registration_deadline) {
+            if opens_at > deadline {
+                return Err(ApiError::bad_request(
+                    "registration_opens_at must not be after registration_deadline",
+                ));
+            }
+        }
+
+        if request.registration_opens_at.is_some_and(|opens_at| opens_at > request.start_time) {
+            return Err(ApiError::bad_request("registration_opens_at must not be after start_time"));
+        }
+
+        if request.registration_deadline.is_some_and(|deadline| deadline > request.start_time) {
+            return Err(ApiError::bad_request("registration_deadline must not be after start_time"));
+        }
+
+        let min_participants = tournament_type.min_participants();
+        if request.max_participants < min_participants {
+            return Err(ApiError::bad_request(format!(
+                "{} tournaments need at least {} participants",
+                tournament_type, min_participants
+            )));
+        }
+
+        if request.max_participants > MAX_TOURNAMENT_PARTICIPANTS {
+            return Err(ApiError::bad_request(format!(
+                "max_participants cannot exceed {}",
+                MAX_TOURNAMENT_PARTICIPANTS
+            )));
+        }
+
+        let is_power_of_two = u32::try_from(request.max_participants).is_ok_and(u32::is_power_of_two);
+        if tournament_type.is_elimination() && !is_power_of_two {
+            return Err(ApiError::bad_request(format!(
+                "{} tournaments need a power-of-two max_participants, got {}",
+                tournament_type, request.max_participants
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::fixtures::at;
+
+    fn request(tournament_type: &str, max_participants: i32) -> CreateTournamentRequest {
+        CreateTournamentRequest {
+            name: "Weekly Cup".to_string(),
+            description: None,
+            game_type: "chess".to_string(),
+            tournament_type: tournament_type.to_string(),
+            entry_fee: 10,
+            max_participants,
+            visibility: "public".to_string(),
+            registration_opens_at: Some(at(100)),
+            registration_deadline: Some(at(200)),
+            start_time: at(300),
+        }
+    }
+
+    fn assert_bad_request(request: &CreateTournamentRequest) {
+        let err = TournamentService::validate_tournament_creation(request).unwrap_err();
+        assert_eq!(err.code, "BAD_REQUEST", "{}", err);
+    }
+
+    #[test]
+    fn rejects_registration_opening_after_deadline() {
+        let mut request = request("single_elimination", 8);
+        request.registration_opens_at = Some(at(250));
+        assert_bad_request(&request);
+    }
+
+    #[test]
+    fn rejects_registration_deadline_after_start() {
+        let mut request = request("single_elimination", 8);
+        request.registration_deadline = Some(at(400));
+        assert_bad_request(&request);
+    }
+
+    #[test]
+    fn rejects_registration_opening_after_start_without_deadline() {
+        let mut request = request("single_elimination", 8);
+        request.registration_deadline = None;
+        request.registration_opens_at = Some(at(400));
+        assert_bad_request(&request);
+    }
+
+    #[test]
+    fn accepts_power_of_two_single_elimination() {
+        assert!(TournamentService::validate_tournament_creation(&request("single_elimination", 8)).is_ok());
+    }
+
+    #[test]
+    fn rejects_non_power_of_two_single_elimination() {
+        assert_bad_request(&request("single_elimination", 3));
+    }
+
+    #[test]
+    fn rejects_oversized_round_robin() {
+        assert_bad_request(&request("round_robin", MAX_TOURNAMENT_PARTICIPANTS + 1));
+    }
+
+    #[test]
+    fn rejects_unknown_tournament_type() {
+        assert_bad_request(&request("king_of_the_hill", 8));
+    }
+
+    #[test]
+    fn rejects_count_below_type_minimum() {
+        assert_bad_request(&request("double_elimination", 2));
+        assert_bad_request(&request("round_robin", 2));
+    }
 }
\ No newline at end of file